@@ -0,0 +1,98 @@
+// Call-graph index built from the functions the parser recovers.
+//
+// For each function we scan its body for identifiers that resolve to other
+// known functions, preferring names in the enclosing module before falling
+// back to the crate root. The result is a directed graph that can answer
+// "who calls X" and "what does X call", including transitively.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::parser::{self, Function, SymbolTable};
+
+/// A call-graph index over a parsed source file.
+pub struct CallGraph {
+    /// Callees reachable directly from each function, keyed by qualified path.
+    callees: HashMap<String, Vec<String>>,
+    /// Callers of each function, keyed by qualified path.
+    callers: HashMap<String, Vec<String>>,
+}
+
+impl CallGraph {
+    /// Parses `source` and builds its call graph.
+    pub fn build(source: &str) -> Self {
+        Self::from_functions(&parser::parse(source).functions, source)
+    }
+
+    /// Builds a call graph from an already-parsed list of functions.
+    pub fn from_functions(functions: &[Function], source: &str) -> Self {
+        let table = SymbolTable::build(functions);
+
+        let mut callees: HashMap<String, Vec<String>> = HashMap::new();
+        let mut callers: HashMap<String, Vec<String>> = HashMap::new();
+        for f in functions {
+            let path = f.qualified_path();
+            callees.entry(path.clone()).or_default();
+            callers.entry(path.clone()).or_default();
+        }
+
+        for f in functions {
+            let caller_path = f.qualified_path();
+            let body = &source[f.body_span.clone()];
+            for name in parser::called_identifiers(body) {
+                if let Some(callee) = table.resolve_call(&f.module_path, name) {
+                    if callee.qualified_path() == caller_path {
+                        continue; // skip direct recursion edges to self
+                    }
+                    let callee_path = callee.qualified_path();
+                    callees.entry(caller_path.clone()).or_default().push(callee_path.clone());
+                    callers.entry(callee_path).or_default().push(caller_path.clone());
+                }
+            }
+        }
+
+        CallGraph { callees, callers }
+    }
+
+    /// Functions called directly by `symbol`.
+    pub fn callees(&self, symbol: &str) -> &[String] {
+        self.callees.get(symbol).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Functions that directly call `symbol`.
+    pub fn callers(&self, symbol: &str) -> &[String] {
+        self.callers.get(symbol).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// All functions reachable from `symbol` by following calls outward,
+    /// not including `symbol` itself.
+    pub fn callees_transitive(&self, symbol: &str) -> Vec<String> {
+        self.transitive_closure(symbol, &self.callees)
+    }
+
+    /// All functions that reach `symbol` by following calls inward,
+    /// not including `symbol` itself.
+    pub fn callers_transitive(&self, symbol: &str) -> Vec<String> {
+        self.transitive_closure(symbol, &self.callers)
+    }
+
+    fn transitive_closure(
+        &self,
+        symbol: &str,
+        edges: &HashMap<String, Vec<String>>,
+    ) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(symbol.to_string());
+        seen.insert(symbol.to_string());
+        let mut result = Vec::new();
+        while let Some(current) = queue.pop_front() {
+            for next in edges.get(&current).map(Vec::as_slice).unwrap_or(&[]) {
+                if seen.insert(next.clone()) {
+                    result.push(next.clone());
+                    queue.push_back(next.clone());
+                }
+            }
+        }
+        result
+    }
+}
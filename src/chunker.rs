@@ -0,0 +1,106 @@
+// Scope-aware chunking.
+//
+// Turns the parser's flat function/module lists into a hierarchical tree of
+// spans: each `mod` becomes a parent node containing its items, and a
+// function nested inside another function becomes a child span of its
+// enclosing function. Byte ranges nest cleanly (a child's span always falls
+// inside its parent's), so the tree can be built purely from containment,
+// without re-scanning the source.
+
+use std::ops::Range;
+
+use crate::parser::{ParsedSource, Visibility};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkKind {
+    Fn,
+    Mod,
+}
+
+/// One node in the chunk tree: a single logical unit (a function body or a
+/// module) along with everything nested inside it.
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    pub kind: ChunkKind,
+    pub qualified_path: String,
+    pub visibility: Visibility,
+    pub span: Range<usize>,
+    pub children: Vec<Chunk>,
+}
+
+struct Item {
+    span: Range<usize>,
+    kind: ChunkKind,
+    qualified_path: String,
+    visibility: Visibility,
+}
+
+/// Builds the top-level chunks (and their nested children) for everything
+/// `parsed` recovered from a source file.
+pub fn build_tree(parsed: &ParsedSource) -> Vec<Chunk> {
+    let mut items: Vec<Item> = parsed
+        .functions
+        .iter()
+        .map(|f| Item {
+            span: f.span.clone(),
+            kind: ChunkKind::Fn,
+            qualified_path: f.qualified_path(),
+            visibility: f.visibility,
+        })
+        .chain(parsed.modules.iter().map(|m| Item {
+            span: m.span.clone(),
+            kind: ChunkKind::Mod,
+            qualified_path: m.qualified_path(),
+            visibility: m.visibility,
+        }))
+        .collect();
+    // Parents always start before their children, so sorting by start puts
+    // each parent ahead of everything it contains.
+    items.sort_by_key(|item| item.span.start);
+
+    let mut stack: Vec<(Item, Vec<Chunk>)> = Vec::new();
+    let mut roots = Vec::new();
+    for item in items {
+        close_finished(&mut stack, &mut roots, item.span.start);
+        stack.push((item, Vec::new()));
+    }
+    close_finished(&mut stack, &mut roots, usize::MAX);
+    roots
+}
+
+/// Pops every open scope that doesn't contain `until`, attaching each as a
+/// child of whatever scope now sits beneath it (or as a root, if none does).
+fn close_finished(stack: &mut Vec<(Item, Vec<Chunk>)>, roots: &mut Vec<Chunk>, until: usize) {
+    while let Some((item, _)) = stack.last() {
+        if item.span.end > until {
+            break;
+        }
+        let (item, children) = stack.pop().unwrap();
+        let chunk = Chunk {
+            kind: item.kind,
+            qualified_path: item.qualified_path,
+            visibility: item.visibility,
+            span: item.span,
+            children,
+        };
+        match stack.last_mut() {
+            Some((_, parent_children)) => parent_children.push(chunk),
+            None => roots.push(chunk),
+        }
+    }
+}
+
+/// Finds the chunk with the given qualified path anywhere in the tree, so a
+/// single logical unit (e.g. `test_module`, or `complex_operation`) can be
+/// pulled out on its own.
+pub fn find<'a>(chunks: &'a [Chunk], qualified_path: &str) -> Option<&'a Chunk> {
+    for chunk in chunks {
+        if chunk.qualified_path == qualified_path {
+            return Some(chunk);
+        }
+        if let Some(found) = find(&chunk.children, qualified_path) {
+            return Some(found);
+        }
+    }
+    None
+}
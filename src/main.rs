@@ -1,5 +1,10 @@
 // test.rs
 
+mod callgraph;
+mod chunker;
+mod parser;
+mod resolver;
+
 // A simple function that adds two numbers.
 fn add(a: i32, b: i32) -> i32 {
     a + b
@@ -89,4 +94,105 @@ fn main() {
 
     // Call functions from the nested module.
     test_module::run_tests();
+
+    // Build a call-graph index so the "spotlight" around a function can be
+    // found: who it calls, and who calls it, transitively.
+    let source = include_str!("main.rs");
+    let parsed = parser::parse(source);
+    let functions = &parsed.functions;
+    for f in functions {
+        println!(
+            "{:?} {} spans bytes {}..{}",
+            f.visibility,
+            f.qualified_path(),
+            f.span.start,
+            f.span.end
+        );
+    }
+    for m in &parsed.modules {
+        println!(
+            "{:?} mod {} spans bytes {}..{}, body {}..{}",
+            m.visibility,
+            m.qualified_path(),
+            m.span.start,
+            m.span.end,
+            m.body_span.start,
+            m.body_span.end
+        );
+    }
+    let graph = callgraph::CallGraph::build(source);
+    println!(
+        "complex_operation calls: {:?}",
+        graph.callees("complex_operation")
+    );
+    println!("callers of add: {:?}", graph.callers("add"));
+    println!(
+        "everything main eventually reaches: {:?}",
+        graph.callees_transitive("main")
+    );
+    println!(
+        "everything that eventually reaches add: {:?}",
+        graph.callers_transitive("add")
+    );
+
+    // Visibility-aware resolution: `test_module::helper` is private, so it's
+    // only visible from inside `test_module` (or a descendant of it).
+    let root: Vec<String> = Vec::new();
+    let test_module_scope = vec!["test_module".to_string()];
+    if let Some(helper) = functions
+        .iter()
+        .find(|f| f.qualified_path() == "test_module::helper")
+    {
+        println!(
+            "test_module::helper visible from crate root: {}",
+            resolver::is_visible_from(helper, &root)
+        );
+        println!(
+            "test_module::helper visible from test_module: {}",
+            resolver::is_visible_from(helper, &test_module_scope)
+        );
+    }
+    println!(
+        "self::helper from test_module resolves to: {:?}",
+        resolver::resolve_path(&test_module_scope, "self::helper")
+    );
+    for err in resolver::find_visibility_errors(functions, source) {
+        println!(
+            "visibility error: {} calls private item {}",
+            err.caller, err.callee
+        );
+    }
+    // A call to a private item from outside its module: the textbook case
+    // `find_visibility_errors` exists to catch.
+    let bad_example = "mod test_module {\n    fn helper() {}\n}\n\nfn main() {\n    test_module::helper();\n}\n";
+    let bad_example_functions = parser::parse(bad_example).functions;
+    for err in resolver::find_visibility_errors(&bad_example_functions, bad_example) {
+        println!(
+            "example visibility error: {} calls private item {}",
+            err.caller, err.callee
+        );
+    }
+
+    // Scope-aware chunking: a hierarchical tree of spans, so a single
+    // logical unit (e.g. the whole `test_module` subtree) can be pulled out
+    // on its own.
+    let chunks = chunker::build_tree(&parsed);
+    if let Some(test_module) = chunker::find(&chunks, "test_module") {
+        println!(
+            "{:?} test_module subtree spans bytes {}..{} with {} children",
+            test_module.visibility,
+            test_module.span.start,
+            test_module.span.end,
+            test_module.children.len()
+        );
+    }
+    if let Some(complex_operation) = chunker::find(&chunks, "complex_operation") {
+        println!(
+            "{:?} {:?} complex_operation spans bytes {}..{}",
+            complex_operation.kind,
+            complex_operation.visibility,
+            complex_operation.span.start,
+            complex_operation.span.end
+        );
+    }
 }
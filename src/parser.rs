@@ -0,0 +1,345 @@
+// Lightweight source scanner for Rust fixtures like `main.rs`.
+//
+// This is not a full Rust parser: it recognizes `fn` and `mod` items by
+// scanning token boundaries and tracking brace nesting, recording each
+// item's span, visibility and enclosing module path. That's enough to build
+// the module-aware symbol table the call graph and visibility resolver need,
+// and the span tree the chunker builds.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Whether an item is reachable from outside its enclosing module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+/// A parsed `fn` item.
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    /// Path of the enclosing modules, not including `name` itself.
+    pub module_path: Vec<String>,
+    pub visibility: Visibility,
+    /// Byte range of the whole item, from `fn` through the closing brace.
+    pub span: Range<usize>,
+    /// Byte range of the `{ ... }` body.
+    pub body_span: Range<usize>,
+}
+
+impl Function {
+    /// The fully qualified path, e.g. `test_module::run_tests`.
+    pub fn qualified_path(&self) -> String {
+        if self.module_path.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}::{}", self.module_path.join("::"), self.name)
+        }
+    }
+}
+
+/// A parsed `mod` item.
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub name: String,
+    /// Path of the enclosing modules, not including `name` itself.
+    pub module_path: Vec<String>,
+    pub visibility: Visibility,
+    /// Byte range of the whole item, from `mod` through the closing brace.
+    pub span: Range<usize>,
+    /// Byte range of the `{ ... }` body.
+    pub body_span: Range<usize>,
+}
+
+impl Module {
+    /// The fully qualified path, e.g. `outer::inner`.
+    pub fn qualified_path(&self) -> String {
+        if self.module_path.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}::{}", self.module_path.join("::"), self.name)
+        }
+    }
+}
+
+/// Everything recovered from scanning a source file.
+#[derive(Debug, Default)]
+pub struct ParsedSource {
+    pub functions: Vec<Function>,
+    pub modules: Vec<Module>,
+}
+
+/// An index of parsed functions by defining module and by fully qualified
+/// path, used to resolve call-site identifiers.
+pub struct SymbolTable<'a> {
+    by_module: HashMap<&'a [String], HashMap<&'a str, &'a Function>>,
+    by_qualified: HashMap<String, &'a Function>,
+}
+
+impl<'a> SymbolTable<'a> {
+    pub fn build(functions: &'a [Function]) -> Self {
+        let mut by_module: HashMap<&'a [String], HashMap<&'a str, &'a Function>> = HashMap::new();
+        let mut by_qualified = HashMap::new();
+        for f in functions {
+            by_module
+                .entry(f.module_path.as_slice())
+                .or_default()
+                .insert(f.name.as_str(), f);
+            by_qualified.insert(f.qualified_path(), f);
+        }
+        SymbolTable { by_module, by_qualified }
+    }
+
+    /// Resolves a call-site identifier written inside `scope`: a bare name
+    /// resolves within `scope`, falling back to the crate root; an
+    /// already-qualified path resolves directly against the crate's symbol
+    /// table.
+    pub fn resolve_call(&self, scope: &[String], name: &str) -> Option<&'a Function> {
+        if name.contains("::") {
+            return self.by_qualified.get(name).copied();
+        }
+        if let Some(f) = self.by_module.get(scope).and_then(|m| m.get(name)) {
+            return Some(*f);
+        }
+        self.by_module.get(&[][..]).and_then(|m| m.get(name)).copied()
+    }
+
+    /// Looks up a function by its absolute module path and name.
+    pub fn get(&self, module_path: &[String], name: &str) -> Option<&'a Function> {
+        self.by_module.get(module_path).and_then(|m| m.get(name)).copied()
+    }
+}
+
+enum ScopeKind {
+    Fn,
+    Mod,
+}
+
+struct OpenScope {
+    kind: ScopeKind,
+    name: String,
+    module_path: Vec<String>,
+    visibility: Visibility,
+    start: usize,
+    body_start: usize,
+    depth: u32,
+}
+
+fn is_ident_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn starts_with_word(source: &str, at: usize, word: &str) -> bool {
+    if !source.as_bytes()[at..].starts_with(word.as_bytes()) {
+        return false;
+    }
+    let bytes = source.as_bytes();
+    let before_ok = at == 0 || !is_ident_char(bytes[at - 1]);
+    let after = at + word.len();
+    let after_ok = after >= bytes.len() || !is_ident_char(bytes[after]);
+    before_ok && after_ok
+}
+
+fn read_ident(source: &str, at: usize) -> (String, usize) {
+    let bytes = source.as_bytes();
+    let mut end = at;
+    while end < bytes.len() && is_ident_char(bytes[end]) {
+        end += 1;
+    }
+    (source[at..end].to_string(), end)
+}
+
+/// Index of `pub`'s start if `i` is directly preceded by it, skipping whitespace.
+fn pub_prefix_start(source: &str, i: usize) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let mut j = i;
+    while j > 0 && bytes[j - 1].is_ascii_whitespace() {
+        j -= 1;
+    }
+    if j >= 3 && &source[j - 3..j] == "pub" {
+        let before = j.checked_sub(4).map(|k| bytes[k]);
+        if before.is_none_or(|b| !is_ident_char(b)) {
+            return Some(j - 3);
+        }
+    }
+    None
+}
+
+/// Scans `source` for `fn` and `mod` items, recording each one's span and
+/// the module path of its enclosing scope.
+pub fn parse(source: &str) -> ParsedSource {
+    let bytes = source.as_bytes();
+    let len = bytes.len();
+    let mut out = ParsedSource::default();
+    let mut mod_path: Vec<String> = Vec::new();
+    let mut open: Vec<OpenScope> = Vec::new();
+    let mut i = 0usize;
+
+    while i < len {
+        // Skip line comments so `// fn foo` never looks like an item.
+        if source.as_bytes()[i..].starts_with(b"//") {
+            i = source[i..].find('\n').map(|nl| i + nl + 1).unwrap_or(len);
+            continue;
+        }
+        // Skip string literals so `"mod foo { fn bar() {} }"` in a string
+        // doesn't look like a real item.
+        if bytes[i] == b'"' {
+            i += 1;
+            while i < len && bytes[i] != b'"' {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i += 1;
+            continue;
+        }
+
+        if let Some(top) = open.last_mut() {
+            if bytes[i] == b'{' {
+                top.depth += 1;
+                i += 1;
+                continue;
+            }
+            if bytes[i] == b'}' {
+                top.depth -= 1;
+                if top.depth == 0 {
+                    let scope = open.pop().unwrap();
+                    let span = scope.start..i + 1;
+                    let body_span = scope.body_start..i + 1;
+                    match scope.kind {
+                        ScopeKind::Fn => out.functions.push(Function {
+                            name: scope.name,
+                            module_path: scope.module_path,
+                            visibility: scope.visibility,
+                            span,
+                            body_span,
+                        }),
+                        ScopeKind::Mod => {
+                            out.modules.push(Module {
+                                name: scope.name,
+                                module_path: scope.module_path,
+                                visibility: scope.visibility,
+                                span,
+                                body_span,
+                            });
+                            mod_path.pop();
+                        }
+                    }
+                }
+                i += 1;
+                continue;
+            }
+        }
+
+        if starts_with_word(source, i, "fn") || starts_with_word(source, i, "mod") {
+            let is_fn = starts_with_word(source, i, "fn");
+            let kw_len = if is_fn { 2 } else { 3 };
+            let pub_start = pub_prefix_start(source, i);
+            let item_start = pub_start.unwrap_or(i);
+            let visibility = if pub_start.is_some() {
+                Visibility::Public
+            } else {
+                Visibility::Private
+            };
+            let mut j = i + kw_len;
+            while j < len && bytes[j].is_ascii_whitespace() {
+                j += 1;
+            }
+            let (name, after_name) = read_ident(source, j);
+            if name.is_empty() {
+                i += kw_len;
+                continue;
+            }
+            // Find the item's opening brace, skipping over a `fn`'s
+            // parameter list / return type. A `;` reached first means this
+            // is a body-less item (e.g. `mod other;` pointing at another
+            // file, or a trait fn signature) - nothing to record.
+            let brace = source[after_name..].find('{').map(|p| after_name + p);
+            let semi = source[after_name..].find(';').map(|p| after_name + p);
+            let has_body = match (brace, semi) {
+                (Some(b), Some(s)) => b < s,
+                (Some(_), None) => true,
+                _ => false,
+            };
+            match has_body.then_some(brace.unwrap_or(after_name)) {
+                Some(bp) => {
+                    open.push(OpenScope {
+                        kind: if is_fn { ScopeKind::Fn } else { ScopeKind::Mod },
+                        name: name.clone(),
+                        module_path: mod_path.clone(),
+                        visibility,
+                        start: item_start,
+                        body_start: bp,
+                        depth: 1,
+                    });
+                    if !is_fn {
+                        mod_path.push(name);
+                    }
+                    i = bp + 1;
+                }
+                None => {
+                    i = semi.map(|s| s + 1).unwrap_or(after_name);
+                }
+            }
+            continue;
+        }
+
+        i += 1;
+    }
+
+    out
+}
+
+/// Extracts plausible call targets from a function body: identifiers
+/// followed by `(` (optionally after whitespace), including qualified paths like
+/// `test_module::run_tests`.
+pub(crate) fn called_identifiers(body: &str) -> Vec<&str> {
+    let bytes = body.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'"' {
+            // Skip string literals so format strings like "foo(" don't look
+            // like calls.
+            i += 1;
+            while i < bytes.len() && bytes[i] != b'"' {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i += 1;
+            continue;
+        }
+        if bytes[i].is_ascii_alphabetic() || bytes[i] == b'_' {
+            let start = i;
+            let mut end = i;
+            while end < bytes.len() {
+                if is_path_sep(bytes, end) {
+                    end += 2;
+                } else if bytes[end].is_ascii_alphanumeric() || bytes[end] == b'_' {
+                    end += 1;
+                } else {
+                    break;
+                }
+            }
+            // Trim a trailing `::` that isn't followed by another segment.
+            let mut seg_end = end;
+            while seg_end > start && body.as_bytes()[seg_end - 1] == b':' {
+                seg_end -= 1;
+            }
+            let mut j = seg_end;
+            while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+                j += 1;
+            }
+            if j < bytes.len() && bytes[j] == b'(' {
+                result.push(&body[start..seg_end]);
+            }
+            i = end.max(start + 1);
+        } else {
+            i += 1;
+        }
+    }
+    result
+}
+
+fn is_path_sep(bytes: &[u8], at: usize) -> bool {
+    bytes[at] == b':' && bytes.get(at + 1) == Some(&b':')
+}
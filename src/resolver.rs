@@ -0,0 +1,77 @@
+// Module-path and visibility-aware symbol resolution.
+//
+// Builds on the parser's module tracking to answer "what does this path
+// refer to" (handling `self::`, `super::`, and `crate::` prefixes) and "can
+// this call site see that item" (Rust's usual module-private visibility:
+// a private item is visible in its defining module and any module nested
+// inside it).
+
+use crate::parser::{self, Function, SymbolTable, Visibility};
+
+/// Resolves `path`, as written inside `from_module`, to an absolute module
+/// path and symbol name.
+pub fn resolve_path(from_module: &[String], path: &str) -> (Vec<String>, String) {
+    let mut segments: Vec<&str> = path.split("::").collect();
+    let mut base = from_module.to_vec();
+
+    if segments.first() == Some(&"crate") {
+        segments.remove(0);
+        base.clear();
+    } else if segments.first() == Some(&"self") {
+        segments.remove(0);
+    } else {
+        while segments.first() == Some(&"super") {
+            segments.remove(0);
+            base.pop();
+        }
+    }
+
+    let name = segments.pop().unwrap_or_default().to_string();
+    base.extend(segments.into_iter().map(String::from));
+    (base, name)
+}
+
+/// Whether `function` can be referenced from code inside `from_module`.
+pub fn is_visible_from(function: &Function, from_module: &[String]) -> bool {
+    match function.visibility {
+        Visibility::Public => true,
+        Visibility::Private => from_module.starts_with(function.module_path.as_slice()),
+    }
+}
+
+/// A call site that references a symbol it cannot see.
+#[derive(Debug, Clone)]
+pub struct VisibilityError {
+    pub caller: String,
+    pub callee: String,
+}
+
+/// Scans every function body for calls that resolve to a private item
+/// outside of its visibility, e.g. a call to `test_module::helper` from
+/// `main`.
+pub fn find_visibility_errors(functions: &[Function], source: &str) -> Vec<VisibilityError> {
+    let table = SymbolTable::build(functions);
+    let mut errors = Vec::new();
+
+    for f in functions {
+        let body = &source[f.body_span.clone()];
+        for name in parser::called_identifiers(body) {
+            let callee = if name.contains("::") {
+                let (module_path, symbol) = resolve_path(&f.module_path, name);
+                table.get(&module_path, &symbol)
+            } else {
+                table.resolve_call(&f.module_path, name)
+            };
+            if let Some(callee) = callee {
+                if !is_visible_from(callee, &f.module_path) {
+                    errors.push(VisibilityError {
+                        caller: f.qualified_path(),
+                        callee: callee.qualified_path(),
+                    });
+                }
+            }
+        }
+    }
+
+    errors
+}